@@ -0,0 +1,48 @@
+//! Minimal use of the `window` crate's public API: open a window and draw an indexed quad,
+//! reloading its shaders on the fly if `shaders/triangle.{vert,frag}` changes.
+
+use std::sync::Arc;
+
+use window::{Mesh, Renderer, Vertex};
+use winit::{
+    event::{Event, WindowEvent},
+    event_loop::{ControlFlow, EventLoop},
+    window::WindowBuilder,
+};
+
+fn main() {
+    let event_loop = EventLoop::new();
+    let window = Arc::new(WindowBuilder::new().build(&event_loop).unwrap());
+
+    let mut renderer = Renderer::new(window.clone());
+    let quad = Mesh::indexed(
+        renderer.memory_allocator(),
+        vec![
+            Vertex { position: [-0.5, -0.5] },
+            Vertex { position: [0.5, -0.5] },
+            Vertex { position: [0.5, 0.5] },
+            Vertex { position: [-0.5, 0.5] },
+        ],
+        vec![0, 1, 2, 2, 3, 0],
+    );
+
+    event_loop.run(move |event, _, control_flow| match event {
+        Event::WindowEvent {
+            event: WindowEvent::CloseRequested,
+            ..
+        } => {
+            *control_flow = ControlFlow::Exit;
+        }
+        Event::WindowEvent {
+            event: WindowEvent::Resized(_),
+            ..
+        } => renderer.resize(),
+        Event::RedrawEventsCleared => {
+            if renderer.begin_frame() {
+                renderer.draw_mesh(&quad);
+                renderer.end_frame();
+            }
+        }
+        _ => (),
+    });
+}
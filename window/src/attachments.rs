@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use vulkano::{
+    device::Device,
+    format::Format,
+    image::{
+        view::ImageView, Image, ImageCreateInfo, ImageType, ImageUsage, SampleCount,
+    },
+    memory::allocator::{AllocationCreateInfo, MemoryTypeFilter, StandardMemoryAllocator},
+    render_pass::{Framebuffer, FramebufferCreateInfo, RenderPass},
+};
+
+/// Sample count and depth format, needed both to build the render pass and to size the offscreen
+/// images it renders into. Kept together since the render pass and those images must always
+/// agree on both.
+#[derive(Clone, Copy)]
+pub(crate) struct AttachmentConfig {
+    pub(crate) samples: SampleCount,
+    pub(crate) depth_format: Format,
+}
+
+/// Builds the render pass for `color_format`, with a depth-stencil attachment and, when
+/// `config.samples` is more than one, a multisampled color attachment resolved into the
+/// swapchain image. `single_pass_renderpass!` produces a plain `Arc<RenderPass>` either way, so
+/// both arms return the same type and resizing or reloading never has to care which one is live.
+pub(crate) fn create_render_pass(
+    device: Arc<Device>,
+    color_format: Format,
+    config: AttachmentConfig,
+) -> Arc<RenderPass> {
+    if config.samples == SampleCount::Sample1 {
+        vulkano::single_pass_renderpass!(
+            device,
+            attachments: {
+                color: {
+                    format: color_format,
+                    samples: 1,
+                    load_op: Clear,
+                    store_op: Store,
+                },
+                depth_stencil: {
+                    format: config.depth_format,
+                    samples: 1,
+                    load_op: Clear,
+                    store_op: DontCare,
+                },
+            },
+            pass: {
+                color: [color],
+                depth_stencil: {depth_stencil},
+            },
+        )
+        .unwrap()
+    } else {
+        vulkano::single_pass_renderpass!(
+            device,
+            attachments: {
+                color: {
+                    format: color_format,
+                    samples: config.samples,
+                    load_op: Clear,
+                    store_op: DontCare,
+                },
+                depth_stencil: {
+                    format: config.depth_format,
+                    samples: config.samples,
+                    load_op: Clear,
+                    store_op: DontCare,
+                },
+                color_resolve: {
+                    format: color_format,
+                    samples: 1,
+                    load_op: DontCare,
+                    store_op: Store,
+                },
+            },
+            pass: {
+                color: [color],
+                color_resolve: [color_resolve],
+                depth_stencil: {depth_stencil},
+            },
+        )
+        .unwrap()
+    }
+}
+
+/// The offscreen attachments a frame needs besides the swapchain image itself: a depth buffer
+/// (always) and, with MSAA enabled, a multisampled color buffer that gets resolved into the
+/// swapchain image at the end of the pass. Rebuilt whenever the swapchain is, since both are
+/// sized to the window extent.
+pub(crate) struct Attachments {
+    pub(crate) depth: Arc<ImageView>,
+    pub(crate) msaa_color: Option<Arc<ImageView>>,
+}
+
+pub(crate) fn create_attachments(
+    memory_allocator: Arc<StandardMemoryAllocator>,
+    extent: [u32; 3],
+    color_format: Format,
+    config: AttachmentConfig,
+) -> Attachments {
+    let depth_image = Image::new(
+        memory_allocator.clone(),
+        ImageCreateInfo {
+            image_type: ImageType::Dim2d,
+            format: config.depth_format,
+            extent,
+            samples: config.samples,
+            usage: ImageUsage::DEPTH_STENCIL_ATTACHMENT | ImageUsage::TRANSIENT_ATTACHMENT,
+            ..Default::default()
+        },
+        AllocationCreateInfo {
+            memory_type_filter: MemoryTypeFilter::PREFER_DEVICE,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let depth = ImageView::new_default(depth_image).unwrap();
+
+    let msaa_color = (config.samples != SampleCount::Sample1).then(|| {
+        let image = Image::new(
+            memory_allocator,
+            ImageCreateInfo {
+                image_type: ImageType::Dim2d,
+                format: color_format,
+                extent,
+                samples: config.samples,
+                usage: ImageUsage::COLOR_ATTACHMENT | ImageUsage::TRANSIENT_ATTACHMENT,
+                ..Default::default()
+            },
+            AllocationCreateInfo {
+                memory_type_filter: MemoryTypeFilter::PREFER_DEVICE,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        ImageView::new_default(image).unwrap()
+    });
+
+    Attachments { depth, msaa_color }
+}
+
+/// Assembles one `Framebuffer` per swapchain image, ordering attachment views to match whichever
+/// `create_render_pass` branch built `render_pass`.
+pub(crate) fn create_framebuffers(
+    render_pass: Arc<RenderPass>,
+    swapchain_images: &[Arc<Image>],
+    attachments: &Attachments,
+) -> Vec<Arc<Framebuffer>> {
+    swapchain_images
+        .iter()
+        .map(|image| {
+            let swapchain_view = ImageView::new_default(image.clone()).unwrap();
+
+            let views = match &attachments.msaa_color {
+                Some(msaa_color) => {
+                    vec![msaa_color.clone(), attachments.depth.clone(), swapchain_view]
+                }
+                None => vec![swapchain_view, attachments.depth.clone()],
+            };
+
+            Framebuffer::new(
+                render_pass.clone(),
+                FramebufferCreateInfo {
+                    attachments: views,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        })
+        .collect()
+}
@@ -0,0 +1,195 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use vulkano::{
+    device::physical::PhysicalDeviceType, format::Format, image::SampleCount,
+    swapchain::PresentMode,
+};
+
+const CONFIG_FILE_NAME: &str = "engine_config.scm";
+
+/// Policy knobs that `Renderer::new` reads instead of hardcoding: which GPU kind to prefer, how many
+/// swapchain images to request, whether to vsync, which surface format to ask for, the clear
+/// color, and where on disk to find runtime assets (shaders, for now). Read from
+/// `engine_config.scm` at startup, an S-expression file in the same spirit as khors'
+/// `engine_config.scm`. Any field missing from the file (or the file itself being absent) falls
+/// back to the defaults below, so the app still runs the way it always did.
+#[derive(Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub(crate) struct EngineConfig {
+    pub(crate) preferred_device_type: DeviceTypePreference,
+    /// `None` keeps the current `min_image_count.max(2)` behavior.
+    pub(crate) image_count: Option<u32>,
+    pub(crate) vsync: bool,
+    /// Name of a `vulkano::format::Format` variant, e.g. `"B8G8R8A8_SRGB"`. `None` (or a name we
+    /// don't recognize) keeps the current "first format the surface reports" behavior.
+    pub(crate) surface_format: Option<String>,
+    pub(crate) clear_color: [f32; 4],
+    pub(crate) asset_path: PathBuf,
+    /// 1, 2, 4, or 8. Anything else (or a count the device doesn't support) falls back to 1.
+    pub(crate) msaa_samples: u32,
+    pub(crate) depth_format: DepthFormat,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            preferred_device_type: DeviceTypePreference::DiscreteGpu,
+            image_count: None,
+            vsync: true,
+            surface_format: None,
+            clear_color: [0.0, 0.0, 1.0, 1.0],
+            asset_path: PathBuf::from(env!("CARGO_MANIFEST_DIR")),
+            msaa_samples: 1,
+            depth_format: DepthFormat::D16Unorm,
+        }
+    }
+}
+
+impl EngineConfig {
+    /// Reads `engine_config.scm` from the current directory. A missing file is the normal case
+    /// for anyone who hasn't customized anything, so it's not logged; a present-but-unparsable
+    /// file is a mistake worth telling the user about, so we log and fall back to defaults
+    /// rather than failing to start.
+    pub(crate) fn load() -> Self {
+        Self::load_from(Path::new(CONFIG_FILE_NAME))
+    }
+
+    fn load_from(path: &Path) -> Self {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return Self::default();
+        };
+
+        match serde_lexpr::from_str(&contents) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!(
+                    "failed to parse {}, using default engine config: {e}",
+                    path.display()
+                );
+                Self::default()
+            }
+        }
+    }
+
+    pub(crate) fn present_mode(&self, supported: &[PresentMode]) -> PresentMode {
+        if self.vsync {
+            return PresentMode::Fifo;
+        }
+
+        [PresentMode::Mailbox, PresentMode::Immediate]
+            .into_iter()
+            .find(|mode| supported.contains(mode))
+            .unwrap_or(PresentMode::Fifo)
+    }
+
+    pub(crate) fn surface_format(&self, supported: &[(Format, impl Copy)]) -> Format {
+        self.surface_format
+            .as_deref()
+            .and_then(parse_format)
+            .filter(|wanted| supported.iter().any(|(format, _)| format == wanted))
+            .unwrap_or(supported[0].0)
+    }
+
+    /// Maps `msaa_samples` to the matching `SampleCount`; anything that isn't one of 2/4/8
+    /// (including 1, or any other value) disables MSAA.
+    pub(crate) fn sample_count(&self) -> SampleCount {
+        match self.msaa_samples {
+            8 => SampleCount::Sample8,
+            4 => SampleCount::Sample4,
+            2 => SampleCount::Sample2,
+            _ => SampleCount::Sample1,
+        }
+    }
+}
+
+/// Mirrors the handful of depth formats vulkano examples commonly use.
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum DepthFormat {
+    D16Unorm,
+    D32Sfloat,
+}
+
+impl From<DepthFormat> for Format {
+    fn from(depth_format: DepthFormat) -> Self {
+        match depth_format {
+            DepthFormat::D16Unorm => Format::D16_UNORM,
+            DepthFormat::D32Sfloat => Format::D32_SFLOAT,
+        }
+    }
+}
+
+/// Mirrors `vulkano::device::physical::PhysicalDeviceType` so the preference can be deserialized
+/// without depending on vulkano adding `serde::Deserialize` upstream.
+#[derive(Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum DeviceTypePreference {
+    DiscreteGpu,
+    IntegratedGpu,
+    VirtualGpu,
+    Cpu,
+    Other,
+}
+
+impl From<DeviceTypePreference> for PhysicalDeviceType {
+    fn from(pref: DeviceTypePreference) -> Self {
+        match pref {
+            DeviceTypePreference::DiscreteGpu => PhysicalDeviceType::DiscreteGpu,
+            DeviceTypePreference::IntegratedGpu => PhysicalDeviceType::IntegratedGpu,
+            DeviceTypePreference::VirtualGpu => PhysicalDeviceType::VirtualGpu,
+            DeviceTypePreference::Cpu => PhysicalDeviceType::Cpu,
+            DeviceTypePreference::Other => PhysicalDeviceType::Other,
+        }
+    }
+}
+
+/// Ranks `actual` for `min_by_key`, preserving the original discrete > integrated > virtual >
+/// cpu > other ordering but promoting whichever type the config prefers to the front.
+pub(crate) fn device_type_rank(preferred: DeviceTypePreference, actual: PhysicalDeviceType) -> u32 {
+    if PhysicalDeviceType::from(preferred) == actual {
+        return 0;
+    }
+
+    match actual {
+        PhysicalDeviceType::DiscreteGpu => 1,
+        PhysicalDeviceType::IntegratedGpu => 2,
+        PhysicalDeviceType::VirtualGpu => 3,
+        PhysicalDeviceType::Cpu => 4,
+        PhysicalDeviceType::Other => 5,
+        _ => 6,
+    }
+}
+
+fn parse_format(name: &str) -> Option<Format> {
+    match name {
+        "B8G8R8A8_SRGB" => Some(Format::B8G8R8A8_SRGB),
+        "B8G8R8A8_UNORM" => Some(Format::B8G8R8A8_UNORM),
+        "R8G8B8A8_SRGB" => Some(Format::R8G8B8A8_SRGB),
+        "R8G8B8A8_UNORM" => Some(Format::R8G8B8A8_UNORM),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_shipped_engine_config() {
+        let contents = include_str!("../engine_config.scm");
+        let config: EngineConfig = serde_lexpr::from_str(contents).unwrap();
+
+        assert_eq!(
+            config.preferred_device_type,
+            DeviceTypePreference::DiscreteGpu
+        );
+        assert_eq!(config.image_count, Some(2));
+        assert!(config.vsync);
+        assert_eq!(config.surface_format.as_deref(), Some("B8G8R8A8_SRGB"));
+        assert_eq!(config.clear_color, [0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(config.asset_path, PathBuf::from("."));
+        assert_eq!(config.msaa_samples, 4);
+        assert_eq!(config.depth_format, DepthFormat::D32Sfloat);
+    }
+}
@@ -0,0 +1,8 @@
+mod attachments;
+mod config;
+mod mesh;
+mod renderer;
+mod shader;
+
+pub use mesh::{Mesh, Vertex};
+pub use renderer::Renderer;
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use vulkano::{
+    buffer::{Buffer, BufferContents, BufferCreateInfo, BufferUsage, Subbuffer},
+    memory::allocator::{AllocationCreateInfo, MemoryTypeFilter, StandardMemoryAllocator},
+    pipeline::graphics::vertex_input::Vertex as VertexTrait,
+};
+
+/// A single vertex: just a 2D position for now, matching what the built-in pipeline's vertex
+/// shader consumes. Consumers build `Mesh`es out of these.
+#[derive(BufferContents, VertexTrait, Clone, Copy)]
+#[repr(C)]
+pub struct Vertex {
+    #[format(R32G32_SFLOAT)]
+    pub position: [f32; 2],
+}
+
+/// A drawable piece of geometry: a vertex buffer and an optional index buffer. Indexed meshes
+/// are drawn with `draw_indexed`, letting shared vertices (quads, imported meshes) be stored
+/// once instead of duplicated per triangle.
+pub struct Mesh {
+    pub(crate) vertex_buffer: Subbuffer<[Vertex]>,
+    pub(crate) index_buffer: Option<Subbuffer<[u32]>>,
+}
+
+impl Mesh {
+    /// Builds a mesh from a plain vertex list, with no index buffer. Every entry in `vertices`
+    /// becomes one corner of whatever primitives the draw call assembles.
+    pub fn new(allocator: Arc<StandardMemoryAllocator>, vertices: Vec<Vertex>) -> Self {
+        Self {
+            vertex_buffer: create_buffer(allocator, BufferUsage::VERTEX_BUFFER, vertices),
+            index_buffer: None,
+        }
+    }
+
+    /// Builds a mesh that draws `vertices` via `indices`, so shared vertices only need to be
+    /// uploaded once.
+    pub fn indexed(
+        allocator: Arc<StandardMemoryAllocator>,
+        vertices: Vec<Vertex>,
+        indices: Vec<u32>,
+    ) -> Self {
+        Self {
+            vertex_buffer: create_buffer(allocator.clone(), BufferUsage::VERTEX_BUFFER, vertices),
+            index_buffer: Some(create_buffer(allocator, BufferUsage::INDEX_BUFFER, indices)),
+        }
+    }
+
+    pub(crate) fn vertex_count(&self) -> u32 {
+        self.vertex_buffer.len() as u32
+    }
+
+    pub(crate) fn index_count(&self) -> u32 {
+        self.index_buffer
+            .as_ref()
+            .map(|buffer| buffer.len() as u32)
+            .unwrap_or(0)
+    }
+}
+
+fn create_buffer<T: BufferContents>(
+    allocator: Arc<StandardMemoryAllocator>,
+    usage: BufferUsage,
+    data: Vec<T>,
+) -> Subbuffer<[T]> {
+    Buffer::from_iter(
+        allocator,
+        BufferCreateInfo {
+            usage,
+            ..Default::default()
+        },
+        AllocationCreateInfo {
+            memory_type_filter: MemoryTypeFilter::PREFER_DEVICE
+                | MemoryTypeFilter::HOST_SEQUENTIAL_WRITE,
+            ..Default::default()
+        },
+        data,
+    )
+    .unwrap()
+}
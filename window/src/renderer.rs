@@ -0,0 +1,459 @@
+use std::sync::Arc;
+
+use vulkano::{
+    command_buffer::{
+        allocator::StandardCommandBufferAllocator, AutoCommandBufferBuilder, CommandBufferUsage,
+        PrimaryAutoCommandBuffer, RenderPassBeginInfo, SubpassBeginInfo, SubpassContents,
+    },
+    device::{Device, DeviceCreateInfo, DeviceExtensions, Queue, QueueCreateInfo, QueueFlags},
+    image::{ImageUsage, SampleCount},
+    instance::{Instance, InstanceCreateFlags, InstanceCreateInfo},
+    library::VulkanLibrary,
+    memory::allocator::StandardMemoryAllocator,
+    pipeline::{graphics::viewport::Viewport, GraphicsPipeline, PipelineLayout},
+    render_pass::{Framebuffer, RenderPass},
+    swapchain::{
+        acquire_next_image, Surface, Swapchain, SwapchainCreateInfo, SwapchainPresentInfo,
+    },
+    sync::{self, GpuFuture},
+    Validated, VulkanError,
+};
+use winit::window::Window;
+
+use crate::{
+    attachments::{self, AttachmentConfig, Attachments},
+    config::EngineConfig,
+    mesh::Mesh,
+    shader::ShaderHotReloader,
+};
+
+/// State for the frame currently being recorded, between `begin_frame` and `end_frame`. Kept as
+/// `Renderer`'s own field rather than a value callers hold, so `draw_mesh` doesn't need its own
+/// handle threaded through every call site.
+struct InProgressFrame {
+    builder: AutoCommandBufferBuilder<PrimaryAutoCommandBuffer>,
+    image_index: u32,
+    acquire_future: Box<dyn GpuFuture>,
+}
+
+/// Owns everything needed to render into a window: the Vulkan instance/device/queue, the
+/// swapchain and its per-image framebuffers, the depth/MSAA attachments, and the hot-reloadable
+/// graphics pipeline. Call `begin_frame`, any number of `draw_mesh`es, then `end_frame` once per
+/// rendered frame; call `resize` whenever the window size changes.
+pub struct Renderer {
+    window: Arc<Window>,
+    device: Arc<Device>,
+    queue: Arc<Queue>,
+    memory_allocator: Arc<StandardMemoryAllocator>,
+    command_buffer_allocator: StandardCommandBufferAllocator,
+
+    engine_config: EngineConfig,
+    attachment_config: AttachmentConfig,
+
+    swapchain: Arc<Swapchain>,
+    render_pass: Arc<RenderPass>,
+    frame_attachments: Attachments,
+    framebuffers: Vec<Arc<Framebuffer>>,
+
+    shader_reloader: ShaderHotReloader,
+    pipeline: Arc<GraphicsPipeline>,
+    pipeline_layout: Arc<PipelineLayout>,
+    viewport: Viewport,
+
+    recreate_swapchain: bool,
+    frame_fences: Vec<Option<Box<dyn GpuFuture>>>,
+    in_progress: Option<InProgressFrame>,
+}
+
+impl Renderer {
+    /// Sets up a Vulkan instance, device, and swapchain for `window`, reading policy (preferred
+    /// GPU, vsync, MSAA, ...) from `engine_config.scm` as described in `EngineConfig`.
+    pub fn new(window: Arc<Window>) -> Self {
+        let engine_config = EngineConfig::load();
+
+        let library = VulkanLibrary::new().expect("no local Vulkan library/DLL");
+        let required_extensions = Surface::required_extensions(&window);
+        let instance = Instance::new(
+            library,
+            InstanceCreateInfo {
+                enabled_extensions: required_extensions,
+                ..Default::default()
+            },
+        )
+        .expect("failed to create instance");
+
+        let surface = Surface::from_window(instance.clone(), window.clone()).unwrap();
+
+        let device_extensions = DeviceExtensions {
+            khr_swapchain: true,
+            ..DeviceExtensions::empty()
+        };
+
+        let (physical_device, queue_family_index) = instance
+            .enumerate_physical_devices()
+            .unwrap()
+            .filter(|p| p.supported_extensions().contains(&device_extensions))
+            .filter_map(|p| {
+                p.queue_family_properties()
+                    .iter()
+                    .enumerate()
+                    .position(|(i, q)| {
+                        q.queue_flags.intersects(QueueFlags::GRAPHICS)
+                            && p.surface_support(i as u32, &surface).unwrap_or(false)
+                    })
+                    .map(|i| (p, i as u32))
+            })
+            .min_by_key(|(p, _)| {
+                crate::config::device_type_rank(
+                    engine_config.preferred_device_type,
+                    p.properties().device_type,
+                )
+            })
+            .expect("No suitable physical device found");
+
+        println!(
+            "Using device: {} (type: {:?})",
+            physical_device.properties().device_name,
+            physical_device.properties().device_type,
+        );
+
+        let (device, mut queues) = Device::new(
+            physical_device,
+            DeviceCreateInfo {
+                enabled_extensions: device_extensions,
+                queue_create_infos: vec![QueueCreateInfo {
+                    queue_family_index,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let queue = queues.next().unwrap();
+
+        let (swapchain, images) = {
+            let surface_capabilities = device
+                .physical_device()
+                .surface_capabilities(&surface, Default::default())
+                .unwrap();
+
+            let surface_formats = device
+                .physical_device()
+                .surface_formats(&surface, Default::default())
+                .unwrap();
+            let image_format = engine_config.surface_format(&surface_formats);
+
+            let present_modes = device
+                .physical_device()
+                .surface_present_modes(&surface, Default::default())
+                .unwrap()
+                .collect::<Vec<_>>();
+            let present_mode = engine_config.present_mode(&present_modes);
+
+            let min_image_count = engine_config
+                .image_count
+                .unwrap_or(surface_capabilities.min_image_count.max(2))
+                .clamp(
+                    surface_capabilities.min_image_count,
+                    surface_capabilities.max_image_count.unwrap_or(u32::MAX),
+                );
+
+            Swapchain::new(
+                device.clone(),
+                surface,
+                SwapchainCreateInfo {
+                    min_image_count,
+                    image_format,
+                    image_extent: window.inner_size().into(),
+                    image_usage: ImageUsage::COLOR_ATTACHMENT,
+                    composite_alpha: surface_capabilities
+                        .supported_composite_alpha
+                        .into_iter()
+                        .next()
+                        .unwrap(),
+                    present_mode,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+        };
+
+        let memory_allocator = Arc::new(StandardMemoryAllocator::new_default(device.clone()));
+
+        // A sample count the device can't use for both color and depth falls back to no MSAA.
+        let properties = device.physical_device().properties();
+        let supported_samples =
+            properties.framebuffer_color_sample_counts & properties.framebuffer_depth_sample_counts;
+        let samples = Some(engine_config.sample_count())
+            .filter(|&samples| supported_samples.contains_enum(samples))
+            .unwrap_or(SampleCount::Sample1);
+
+        let attachment_config = AttachmentConfig {
+            samples,
+            depth_format: engine_config.depth_format.into(),
+        };
+
+        let shader_dir = engine_config.asset_path.join("shaders");
+        let mut shader_reloader = ShaderHotReloader::new(
+            shader_dir.join("triangle.vert"),
+            shader_dir.join("triangle.frag"),
+            attachment_config.samples,
+        );
+
+        let render_pass = attachments::create_render_pass(
+            device.clone(),
+            swapchain.image_format(),
+            attachment_config,
+        );
+
+        let (pipeline, pipeline_layout) =
+            shader_reloader.build_initial_pipeline(device.clone(), render_pass.clone());
+
+        let extent = images[0].extent();
+        let viewport = Viewport {
+            offset: [0.0, 0.0],
+            extent: [extent[0] as f32, extent[1] as f32],
+            depth_range: 0.0..=1.0,
+        };
+
+        let frame_attachments = attachments::create_attachments(
+            memory_allocator.clone(),
+            extent,
+            swapchain.image_format(),
+            attachment_config,
+        );
+        let framebuffers =
+            attachments::create_framebuffers(render_pass.clone(), &images, &frame_attachments);
+
+        let command_buffer_allocator =
+            StandardCommandBufferAllocator::new(device.clone(), Default::default());
+        let frame_fences = (0..images.len()).map(|_| None).collect();
+
+        Self {
+            window,
+            device,
+            queue,
+            memory_allocator,
+            command_buffer_allocator,
+            engine_config,
+            attachment_config,
+            swapchain,
+            render_pass,
+            frame_attachments,
+            framebuffers,
+            shader_reloader,
+            pipeline,
+            pipeline_layout,
+            viewport,
+            recreate_swapchain: false,
+            frame_fences,
+            in_progress: None,
+        }
+    }
+
+    /// The allocator meshes should be built with; exposed so callers can create `Mesh`es that
+    /// share this renderer's device.
+    pub fn memory_allocator(&self) -> Arc<StandardMemoryAllocator> {
+        self.memory_allocator.clone()
+    }
+
+    /// Marks the swapchain for recreation at the next `begin_frame`. Call this from a
+    /// `WindowEvent::Resized` handler.
+    pub fn resize(&mut self) {
+        self.recreate_swapchain = true;
+    }
+
+    fn recreate_swapchain_and_attachments(&mut self) {
+        let image_extent: [u32; 2] = self.window.inner_size().into();
+
+        let (new_swapchain, new_images) = self
+            .swapchain
+            .recreate(SwapchainCreateInfo {
+                image_extent,
+                ..self.swapchain.create_info()
+            })
+            .expect("failed to recreate swapchain");
+
+        self.swapchain = new_swapchain;
+
+        self.frame_attachments = attachments::create_attachments(
+            self.memory_allocator.clone(),
+            new_images[0].extent(),
+            self.swapchain.image_format(),
+            self.attachment_config,
+        );
+        self.framebuffers = attachments::create_framebuffers(
+            self.render_pass.clone(),
+            &new_images,
+            &self.frame_attachments,
+        );
+
+        self.viewport.extent = [image_extent[0] as f32, image_extent[1] as f32];
+
+        // None of the new images have an outstanding submission yet, so every fence slot starts
+        // fresh; the new swapchain may not even have the same image count as the old one.
+        self.frame_fences = (0..new_images.len()).map(|_| None).collect();
+
+        self.recreate_swapchain = false;
+    }
+
+    /// Starts recording a new frame: recreates the swapchain if it was marked dirty, checks for a
+    /// hot-reloaded pipeline, acquires the next swapchain image, and begins the render pass.
+    /// Returns `false` (doing nothing else) when the window is minimized or the image couldn't be
+    /// acquired, in which case the caller should skip `draw_mesh`/`end_frame` for this tick.
+    pub fn begin_frame(&mut self) -> bool {
+        assert!(
+            self.in_progress.is_none(),
+            "begin_frame called before the previous frame's end_frame"
+        );
+
+        let image_extent: [u32; 2] = self.window.inner_size().into();
+        if image_extent.contains(&0) {
+            return false;
+        }
+
+        if self.recreate_swapchain {
+            self.recreate_swapchain_and_attachments();
+        }
+
+        if let Some(rebuild) = self.shader_reloader.poll_rebuild(
+            self.device.clone(),
+            self.render_pass.clone(),
+            self.pipeline_layout.clone(),
+        ) {
+            match rebuild {
+                Ok(new_pipeline) => self.pipeline = new_pipeline,
+                Err(e) => eprintln!("shader hot-reload failed, keeping last pipeline: {e}"),
+            }
+        }
+
+        let (image_index, suboptimal, acquire_future) =
+            match acquire_next_image(self.swapchain.clone(), None).map_err(Validated::unwrap) {
+                Ok(r) => r,
+                Err(VulkanError::OutOfDate) => {
+                    self.recreate_swapchain = true;
+                    return false;
+                }
+                Err(e) => panic!("failed to acquire next image: {e}"),
+            };
+
+        if suboptimal {
+            self.recreate_swapchain = true;
+        }
+
+        let image_idx = image_index as usize;
+
+        let mut previous_frame_end = self.frame_fences[image_idx]
+            .take()
+            .unwrap_or_else(|| sync::now(self.device.clone()).boxed());
+        previous_frame_end.cleanup_finished();
+
+        let mut builder = AutoCommandBufferBuilder::primary(
+            &self.command_buffer_allocator,
+            self.queue.queue_family_index(),
+            CommandBufferUsage::OneTimeSubmit,
+        )
+        .unwrap();
+
+        let clear_values = {
+            let mut clear_values =
+                vec![Some(self.engine_config.clear_color.into()), Some(1f32.into())];
+            if self.frame_attachments.msaa_color.is_some() {
+                clear_values.push(None);
+            }
+            clear_values
+        };
+
+        builder
+            .begin_render_pass(
+                RenderPassBeginInfo {
+                    clear_values,
+                    ..RenderPassBeginInfo::framebuffer(self.framebuffers[image_idx].clone())
+                },
+                SubpassBeginInfo {
+                    contents: SubpassContents::Inline,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .set_viewport(0, [self.viewport.clone()].into_iter().collect())
+            .unwrap()
+            .bind_pipeline_graphics(self.pipeline.clone())
+            .unwrap();
+
+        self.in_progress = Some(InProgressFrame {
+            builder,
+            image_index,
+            acquire_future: previous_frame_end.join(acquire_future).boxed(),
+        });
+
+        true
+    }
+
+    /// Records a draw call for `mesh` into the frame started by `begin_frame`. Uses
+    /// `draw_indexed` when the mesh has an index buffer, so shared vertices only need to be
+    /// uploaded once; falls back to a plain `draw` otherwise.
+    pub fn draw_mesh(&mut self, mesh: &Mesh) {
+        let frame = self
+            .in_progress
+            .as_mut()
+            .expect("draw_mesh called without an active begin_frame");
+
+        frame
+            .builder
+            .bind_vertex_buffers(0, mesh.vertex_buffer.clone())
+            .unwrap();
+
+        match &mesh.index_buffer {
+            Some(index_buffer) => {
+                frame
+                    .builder
+                    .bind_index_buffer(index_buffer.clone())
+                    .unwrap()
+                    .draw_indexed(mesh.index_count(), 1, 0, 0, 0)
+                    .unwrap();
+            }
+            None => {
+                frame.builder.draw(mesh.vertex_count(), 1, 0, 0).unwrap();
+            }
+        }
+    }
+
+    /// Ends the render pass, submits the recorded command buffer, and presents the image. Stores
+    /// the resulting future in this image's fence slot so the next frame that reuses this image
+    /// index waits for it first.
+    pub fn end_frame(&mut self) {
+        let InProgressFrame {
+            mut builder,
+            image_index,
+            acquire_future,
+        } = self
+            .in_progress
+            .take()
+            .expect("end_frame called without an active begin_frame");
+
+        builder.end_render_pass(Default::default()).unwrap();
+        let command_buffer = builder.build().unwrap();
+
+        let future = acquire_future
+            .then_execute(self.queue.clone(), command_buffer)
+            .unwrap()
+            .then_swapchain_present(
+                self.queue.clone(),
+                SwapchainPresentInfo::swapchain_image_index(self.swapchain.clone(), image_index),
+            )
+            .then_signal_fence_and_flush();
+
+        let image_idx = image_index as usize;
+        self.frame_fences[image_idx] = match future.map_err(Validated::unwrap) {
+            Ok(future) => Some(future.boxed()),
+            Err(VulkanError::OutOfDate) => {
+                self.recreate_swapchain = true;
+                Some(sync::now(self.device.clone()).boxed())
+            }
+            Err(e) => {
+                panic!("failed to flush future: {e}");
+            }
+        };
+    }
+}
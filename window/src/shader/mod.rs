@@ -0,0 +1,243 @@
+use std::{
+    path::PathBuf,
+    sync::{mpsc::Receiver, Arc},
+    time::Duration,
+};
+
+use notify_debouncer_mini::{
+    new_debouncer, notify::RecursiveMode, DebounceEventResult, Debouncer,
+};
+use vulkano::{
+    device::Device,
+    image::SampleCount,
+    pipeline::{
+        graphics::{
+            color_blend::{ColorBlendAttachmentState, ColorBlendState},
+            depth_stencil::{DepthState, DepthStencilState},
+            input_assembly::InputAssemblyState,
+            multisample::MultisampleState,
+            rasterization::RasterizationState,
+            vertex_input::{Vertex, VertexDefinition},
+            viewport::ViewportState,
+            GraphicsPipelineCreateInfo,
+        },
+        DynamicState, GraphicsPipeline, PipelineLayout, PipelineShaderStageCreateInfo,
+    },
+    render_pass::{RenderPass, Subpass},
+    shader::{ShaderModule, ShaderModuleCreateInfo},
+};
+
+/// Source of a single GLSL stage watched on disk, along with the `shaderc` kind needed to
+/// compile it.
+struct WatchedStage {
+    path: PathBuf,
+    kind: shaderc::ShaderKind,
+}
+
+/// Watches a vertex/fragment GLSL pair on disk and recompiles the pipeline built from them
+/// whenever one of the files changes. Keeping the file-watching logic isolated here means the
+/// render loop only has to ask `poll_rebuild` whether a new pipeline is ready, instead of
+/// knowing anything about `notify` or `shaderc`.
+pub(crate) struct ShaderHotReloader {
+    compiler: shaderc::Compiler,
+    vs: WatchedStage,
+    fs: WatchedStage,
+    samples: SampleCount,
+    // Kept alive for as long as the reloader exists; dropping it stops the watch.
+    _debouncer: Debouncer<notify_debouncer_mini::notify::RecommendedWatcher>,
+    events: Receiver<DebounceEventResult>,
+}
+
+impl ShaderHotReloader {
+    /// Starts watching `vertex_path`/`fragment_path` for changes, debounced by 200ms so editors
+    /// that write a file in several chunks don't trigger multiple recompiles. `samples` is the
+    /// rasterization sample count every rebuilt pipeline must match the render pass's.
+    pub(crate) fn new(
+        vertex_path: impl Into<PathBuf>,
+        fragment_path: impl Into<PathBuf>,
+        samples: SampleCount,
+    ) -> Self {
+        let vs = WatchedStage {
+            path: vertex_path.into(),
+            kind: shaderc::ShaderKind::Vertex,
+        };
+        let fs = WatchedStage {
+            path: fragment_path.into(),
+            kind: shaderc::ShaderKind::Fragment,
+        };
+
+        let (tx, events) = std::sync::mpsc::channel();
+        let mut debouncer = new_debouncer(Duration::from_millis(200), tx)
+            .expect("failed to start shader file watcher");
+
+        for stage in [&vs, &fs] {
+            debouncer
+                .watcher()
+                .watch(&stage.path, RecursiveMode::NonRecursive)
+                .unwrap_or_else(|e| {
+                    panic!("failed to watch shader file {}: {e}", stage.path.display())
+                });
+        }
+
+        let compiler = shaderc::Compiler::new().expect("failed to create shaderc compiler");
+
+        Self {
+            compiler,
+            vs,
+            fs,
+            samples,
+            _debouncer: debouncer,
+            events,
+        }
+    }
+
+    /// Compiles both stages from their current contents on disk. Used for the initial pipeline
+    /// build and whenever a change event fires.
+    fn compile_modules(
+        &mut self,
+        device: Arc<Device>,
+    ) -> Result<(Arc<ShaderModule>, Arc<ShaderModule>), String> {
+        let vs = compile_stage(&mut self.compiler, &self.vs, device.clone())?;
+        let fs = compile_stage(&mut self.compiler, &self.fs, device)?;
+        Ok((vs, fs))
+    }
+
+    /// Builds the initial graphics pipeline from the shaders on disk. Panics on failure since an
+    /// app that can't even start with valid shaders has nothing sensible to fall back to.
+    pub(crate) fn build_initial_pipeline(
+        &mut self,
+        device: Arc<Device>,
+        render_pass: Arc<RenderPass>,
+    ) -> (Arc<GraphicsPipeline>, Arc<PipelineLayout>) {
+        let (vs, fs) = self
+            .compile_modules(device.clone())
+            .expect("initial shader compilation failed");
+        build_pipeline(device, render_pass, None, &vs, &fs, self.samples)
+    }
+
+    /// Non-blocking check for a debounced filesystem event on either watched shader. Returns a
+    /// freshly built pipeline on success. On a compile error the error is returned so the caller
+    /// can log it and keep rendering with the pipeline it already has.
+    pub(crate) fn poll_rebuild(
+        &mut self,
+        device: Arc<Device>,
+        render_pass: Arc<RenderPass>,
+        layout: Arc<PipelineLayout>,
+    ) -> Option<Result<Arc<GraphicsPipeline>, String>> {
+        let mut changed = false;
+        while let Ok(events) = self.events.try_recv() {
+            match events {
+                Ok(events) => changed |= events.iter().any(|e| {
+                    e.path == self.vs.path || e.path == self.fs.path
+                }),
+                Err(errors) => {
+                    for error in errors {
+                        eprintln!("shader watcher error: {error}");
+                    }
+                }
+            }
+        }
+
+        if !changed {
+            return None;
+        }
+
+        let samples = self.samples;
+        Some(self.compile_modules(device.clone()).map(|(vs, fs)| {
+            build_pipeline(device, render_pass, Some(layout), &vs, &fs, samples).0
+        }))
+    }
+}
+
+fn compile_stage(
+    compiler: &mut shaderc::Compiler,
+    stage: &WatchedStage,
+    device: Arc<Device>,
+) -> Result<Arc<ShaderModule>, String> {
+    let source = std::fs::read_to_string(&stage.path)
+        .map_err(|e| format!("reading {}: {e}", stage.path.display()))?;
+
+    let artifact = compiler
+        .compile_into_spirv(
+            &source,
+            stage.kind,
+            &stage.path.to_string_lossy(),
+            "main",
+            None,
+        )
+        .map_err(|e| format!("compiling {}: {e}", stage.path.display()))?;
+
+    unsafe {
+        ShaderModule::new(device, ShaderModuleCreateInfo::new(artifact.as_binary()))
+    }
+    .map_err(|e| format!("loading compiled {}: {e}", stage.path.display()))
+}
+
+/// Rebuilds the `GraphicsPipeline` from freshly loaded shader modules. When `layout` is `Some`
+/// the existing `PipelineLayout` is reused (the hot-reload path); when `None` a new one is
+/// derived from the stages, matching the one-time setup in `Renderer::new`.
+fn build_pipeline(
+    device: Arc<Device>,
+    render_pass: Arc<RenderPass>,
+    layout: Option<Arc<PipelineLayout>>,
+    vs: &Arc<ShaderModule>,
+    fs: &Arc<ShaderModule>,
+    samples: SampleCount,
+) -> (Arc<GraphicsPipeline>, Arc<PipelineLayout>) {
+    let vs = vs.entry_point("main").unwrap();
+    let fs = fs.entry_point("main").unwrap();
+
+    let vertex_input_state = crate::mesh::Vertex::per_vertex()
+        .definition(&vs.info().input_interface)
+        .unwrap();
+
+    let stages = [
+        PipelineShaderStageCreateInfo::new(vs),
+        PipelineShaderStageCreateInfo::new(fs),
+    ]
+    .into_iter()
+    .collect();
+
+    let layout = layout.unwrap_or_else(|| {
+        use vulkano::pipeline::layout::PipelineDescriptorSetLayoutCreateInfo;
+        PipelineLayout::new(
+            device.clone(),
+            PipelineDescriptorSetLayoutCreateInfo::from_stages(&stages)
+                .into_pipeline_layout_create_info(device.clone())
+                .unwrap(),
+        )
+        .unwrap()
+    });
+
+    let subpass = Subpass::from(render_pass, 0).unwrap();
+
+    let pipeline = GraphicsPipeline::new(
+        device,
+        None,
+        GraphicsPipelineCreateInfo {
+            stages,
+            vertex_input_state: Some(vertex_input_state),
+            input_assembly_state: Some(InputAssemblyState::default()),
+            viewport_state: Some(ViewportState::default()),
+            rasterization_state: Some(RasterizationState::default()),
+            multisample_state: Some(MultisampleState {
+                rasterization_samples: samples,
+                ..Default::default()
+            }),
+            depth_stencil_state: Some(DepthStencilState {
+                depth: Some(DepthState::simple()),
+                ..Default::default()
+            }),
+            color_blend_state: Some(ColorBlendState::with_attachment_states(
+                subpass.num_color_attachments(),
+                ColorBlendAttachmentState::default(),
+            )),
+            dynamic_state: [DynamicState::Viewport].into_iter().collect(),
+            subpass: Some(subpass.into()),
+            ..GraphicsPipelineCreateInfo::layout(layout.clone())
+        },
+    )
+    .unwrap();
+
+    (pipeline, layout)
+}